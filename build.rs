@@ -0,0 +1,3 @@
+fn main() {
+    prost_build::compile_protos(&["proto/storage.proto"], &["proto/"]).expect("failed to compile proto/storage.proto");
+}
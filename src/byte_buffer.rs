@@ -0,0 +1,86 @@
+use interoptopus::{ffi_function, ffi_type};
+
+/// An owned, heap-allocated byte buffer returned across the FFI boundary by value.
+///
+/// Unlike [`interoptopus::patterns::slice::FFISliceMut`], the caller does not have to
+/// pre-allocate storage and guess a big-enough size; Rust allocates exactly `len` bytes and hands
+/// ownership to C#. An absent value is represented as `len == 0, data == null`.
+///
+/// # Ownership
+///
+/// The caller must pass every non-empty `ByteBuffer` it receives to [`free_byte_buffer`] exactly
+/// once to release the allocation.
+#[ffi_type]
+#[repr(C)]
+#[derive(Debug)]
+pub struct ByteBuffer {
+    pub len: i64,
+    pub data: *mut u8,
+}
+
+impl ByteBuffer {
+    /// Represents an absent value: `len == 0, data == null`.
+    pub fn empty() -> Self {
+        Self {
+            len: 0,
+            data: std::ptr::null_mut(),
+        }
+    }
+
+    /// Takes ownership of `bytes` without copying, returning a buffer [`free_byte_buffer`] can
+    /// later reconstruct and drop.
+    ///
+    /// `bytes` is normalized into a `Box<[u8]>` first so its capacity always equals `len` -
+    /// `free_byte_buffer` has no field to remember the original `Vec`'s spare capacity in, and
+    /// reconstructing a `Vec` from just `(data, len)` while assuming `capacity == len` would be
+    /// unsound whenever the source `Vec` (e.g. from `String::into_bytes` or
+    /// `prost::Message::encode_to_vec`) had over-allocated.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::empty();
+        }
+
+        let mut boxed = bytes.into_boxed_slice();
+        let buffer = Self {
+            len: boxed.len() as i64,
+            data: boxed.as_mut_ptr(),
+        };
+
+        std::mem::forget(boxed);
+
+        buffer
+    }
+
+    /// Borrows this buffer's contents as a byte slice without copying and without taking
+    /// ownership of the underlying allocation; the caller is still responsible for freeing `self`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be empty or point at a live allocation of `self.len` bytes, i.e. either one
+    /// produced by [`ByteBuffer::from_vec`] and not yet freed, or one received intact from the
+    /// FFI boundary.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        if self.data.is_null() || self.len == 0 {
+            return &[];
+        }
+
+        std::slice::from_raw_parts(self.data, self.len as usize)
+    }
+}
+
+/// Frees a [`ByteBuffer`] previously returned by this library.
+///
+/// The caller must copy the contents out before calling this, and must call it at most once per
+/// non-empty `ByteBuffer`. Freeing an empty (`len == 0, data == null`) buffer is a no-op.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn free_byte_buffer(buf: ByteBuffer) {
+    if buf.data.is_null() || buf.len == 0 {
+        return;
+    }
+
+    unsafe {
+        let slice = std::slice::from_raw_parts_mut(buf.data, buf.len as usize);
+        drop(Box::from_raw(slice as *mut [u8]));
+    }
+}
@@ -0,0 +1,67 @@
+use std::ffi::{c_char, CString};
+
+use interoptopus::{ffi_function, ffi_type};
+
+use crate::{ffi_error::FFIError, EnvError};
+
+/// A richer error payload for services that need to surface more than an error code.
+///
+/// [`FFIError`] only carries a discriminant, so a failure like a UTF-8 conversion error or a
+/// failed [`CString::new`] collapses into a generic [`FFIError::Fail`] with no message. Services
+/// that want to preserve the original [`EnvError`] text can return this instead.
+///
+/// # Ownership
+///
+/// On success `code` is `0` and `message` is null. On failure `message` points at a heap-
+/// allocated, NUL-terminated C string owned by Rust. The caller (C#) must copy the string out of
+/// `message` before calling [`free_extern_error_message`], after which the pointer is dangling.
+#[ffi_type]
+#[repr(C)]
+#[derive(Debug)]
+pub struct ExternError {
+    pub code: i32,
+    pub message: *mut c_char,
+}
+
+impl ExternError {
+    pub fn ok() -> Self {
+        Self {
+            code: FFIError::Ok as i32,
+            message: std::ptr::null_mut(),
+        }
+    }
+
+    fn with_message(code: i32, message: impl std::fmt::Display) -> Self {
+        let message = CString::new(message.to_string())
+            .unwrap_or_else(|_| CString::new("<error message contained a NUL byte>").unwrap());
+
+        Self {
+            code,
+            message: message.into_raw(),
+        }
+    }
+}
+
+impl From<EnvError> for ExternError {
+    fn from(error: EnvError) -> Self {
+        let EnvError::Other(message) = error;
+
+        Self::with_message(FFIError::Fail as i32, message)
+    }
+}
+
+/// Frees a `message` previously returned inside an [`ExternError`].
+///
+/// The caller must copy the message out before calling this, and must call it at most once per
+/// `ExternError` with a non-null `message`. Passing a null pointer is a no-op.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn free_extern_error_message(message: *mut c_char) {
+    if message.is_null() {
+        return;
+    }
+
+    unsafe {
+        drop(CString::from_raw(message));
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+use interoptopus::{ffi_function, patterns::option::FFIOption};
+
+use crate::{byte_buffer::ByteBuffer, handle_map::HandleMap};
+
+/// Opaque handle to a value C# will produce asynchronously (see [`complete_future`]).
+pub type FutureHandle = u64;
+
+enum FutureState {
+    Pending,
+    Ready(ByteBuffer),
+}
+
+// `ByteBuffer` holds a raw pointer and so is not `Send`, but a `Ready` buffer is owned solely by
+// this state until it is taken out, so the state can move between threads.
+unsafe impl Send for FutureState {}
+
+struct PendingFuture {
+    state: Mutex<FutureState>,
+    condvar: Condvar,
+}
+
+fn pending_futures() -> &'static HandleMap<Arc<PendingFuture>> {
+    static PENDING_FUTURES: OnceLock<HandleMap<Arc<PendingFuture>>> = OnceLock::new();
+
+    PENDING_FUTURES.get_or_init(HandleMap::new)
+}
+
+/// Registers a new pending future, returning the handle to hand to C# alongside the callback
+/// that asks it to eventually call [`complete_future`].
+pub fn register() -> FutureHandle {
+    let pending = Arc::new(PendingFuture {
+        state: Mutex::new(FutureState::Pending),
+        condvar: Condvar::new(),
+    });
+
+    pending_futures().insert(pending)
+}
+
+/// Drops a pending future's slot, so a [`complete_future`] call that is already in flight for it
+/// is safely ignored (the generation it was issued for no longer validates).
+///
+/// If the future had already completed but its value was never consumed via [`future_await`]/
+/// [`future_try_take`], that value's `ByteBuffer` is freed here - otherwise it would leak, since
+/// `ByteBuffer` has no `Drop` impl and relies on an explicit free.
+pub fn cancel(future: FutureHandle) {
+    if let Ok(pending) = pending_futures().remove(future) {
+        let state = std::mem::replace(
+            &mut *pending.state.lock().expect("pending future lock poisoned"),
+            FutureState::Pending,
+        );
+
+        if let FutureState::Ready(buffer) = state {
+            crate::byte_buffer::free_byte_buffer(buffer);
+        }
+    }
+}
+
+/// Resolves a pending future with a value sent back from C#, e.g. from `GetStorageAsyncCallback`.
+///
+/// A completion for a stale or cancelled future (handle no longer present, generation mismatch)
+/// is silently ignored, since whoever was awaiting it may already have given up.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn complete_future(future: FutureHandle, value: interoptopus::patterns::string::AsciiPointer) {
+    let bytes = value.as_c_str().map(|cstr| cstr.to_bytes().to_vec()).unwrap_or_default();
+
+    // Keep the value as a plain `Vec<u8>` until a valid slot is confirmed, rather than leaking it
+    // into a `ByteBuffer` (which has no `Drop`) up front: if `future` is stale or cancelled, the
+    // closure below never runs and `bytes` is freed normally when it's dropped.
+    let _ = pending_futures().get(future, move |pending| {
+        let buffer = ByteBuffer::from_vec(bytes);
+        let mut state = pending.state.lock().expect("pending future lock poisoned");
+
+        // A second completion for a handle that's already `Ready` would otherwise overwrite that
+        // buffer here and leak it, the same way an unconsumed one would on removal - free it first.
+        if let FutureState::Ready(stale) = std::mem::replace(&mut *state, FutureState::Ready(buffer)) {
+            crate::byte_buffer::free_byte_buffer(stale);
+        }
+
+        pending.condvar.notify_all();
+    });
+}
+
+/// Blocks the calling thread until `future` is completed (or was never valid), then returns its
+/// value. Returns an empty [`ByteBuffer`] for a stale/unknown handle.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn future_await(future: FutureHandle) -> ByteBuffer {
+    let Ok(pending) = pending_futures().get(future, Arc::clone) else {
+        return ByteBuffer::empty();
+    };
+
+    let buffer = {
+        let mut state = pending.state.lock().expect("pending future lock poisoned");
+
+        while matches!(*state, FutureState::Pending) {
+            state = pending.condvar.wait(state).expect("pending future lock poisoned");
+        }
+
+        match std::mem::replace(&mut *state, FutureState::Pending) {
+            FutureState::Ready(buffer) => buffer,
+            FutureState::Pending => unreachable!("woke from condvar wait while still pending"),
+        }
+    };
+
+    let _ = pending_futures().remove(future);
+
+    buffer
+}
+
+/// Non-blocking counterpart to [`future_await`]: returns `None` if `future` hasn't completed yet
+/// (or is unknown/stale), without waiting for it.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn future_try_take(future: FutureHandle) -> FFIOption<ByteBuffer> {
+    let Ok(pending) = pending_futures().get(future, Arc::clone) else {
+        return FFIOption::none();
+    };
+
+    let ready = {
+        let mut state = pending.state.lock().expect("pending future lock poisoned");
+
+        match std::mem::replace(&mut *state, FutureState::Pending) {
+            FutureState::Ready(buffer) => Some(buffer),
+            FutureState::Pending => None,
+        }
+    };
+
+    match ready {
+        Some(buffer) => {
+            let _ = pending_futures().remove(future);
+
+            FFIOption::some(buffer)
+        }
+        None => FFIOption::none(),
+    }
+}
+
+/// Cancels `future` so that a completion racing in from C# for it is safely dropped.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn future_cancel(future: FutureHandle) {
+    cancel(future);
+}
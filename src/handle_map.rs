@@ -0,0 +1,140 @@
+use std::sync::{
+    atomic::{AtomicU16, Ordering},
+    Mutex, RwLock,
+};
+
+use crate::ffi_error::FFIError;
+
+static NEXT_MAP_ID: AtomicU16 = AtomicU16::new(1);
+
+struct Slot<T> {
+    generation: u16,
+    value: Option<Mutex<T>>,
+}
+
+/// A generation-checked table of Rust values addressed by an opaque 64-bit handle.
+///
+/// Handing C# a raw pointer to a Rust value lets a stale or freed pointer come back into an
+/// `*_free`/`*_get` style function, which is undefined behavior. A `HandleMap` instead hands out
+/// a `handle: u64` packed as `{ map_id: 16, generation: 16, index: 32 }`. [`Self::get`] and
+/// [`Self::get_mut`] reject a handle whose `map_id` doesn't match this map, or whose `generation`
+/// doesn't match the slot's current generation, so a handle from the wrong map, or one pointing
+/// at a slot that has since been removed and reused, never validates again.
+///
+/// The table itself is behind an `RwLock` and each entry behind its own `Mutex`, so C# callers on
+/// different threads can operate on different handles without contending on a single lock.
+pub struct HandleMap<T> {
+    map_id: u16,
+    entries: RwLock<Vec<Slot<T>>>,
+}
+
+impl<T> HandleMap<T> {
+    pub fn new() -> Self {
+        Self {
+            map_id: NEXT_MAP_ID.fetch_add(1, Ordering::Relaxed),
+            entries: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Stores `value` and returns the handle to retrieve it with.
+    pub fn insert(&self, value: T) -> u64 {
+        let mut entries = self.entries.write().expect("handle map lock poisoned");
+
+        if let Some((index, slot)) = entries.iter_mut().enumerate().find(|(_, slot)| slot.value.is_none()) {
+            slot.value = Some(Mutex::new(value));
+
+            return pack(self.map_id, slot.generation, index as u32);
+        }
+
+        let index = entries.len() as u32;
+        entries.push(Slot {
+            generation: 0,
+            value: Some(Mutex::new(value)),
+        });
+
+        pack(self.map_id, 0, index)
+    }
+
+    /// Calls `f` with a shared reference to the value behind `handle`.
+    pub fn get<R>(&self, handle: u64, f: impl FnOnce(&T) -> R) -> Result<R, FFIError> {
+        let (map_id, generation, index) = unpack(handle);
+        if map_id != self.map_id {
+            return Err(FFIError::Null);
+        }
+
+        let entries = self.entries.read().expect("handle map lock poisoned");
+        let slot = entries.get(index as usize).ok_or(FFIError::Null)?;
+
+        if slot.generation != generation {
+            return Err(FFIError::Null);
+        }
+
+        let value = slot.value.as_ref().ok_or(FFIError::Null)?;
+
+        Ok(f(&value.lock().expect("handle map lock poisoned")))
+    }
+
+    /// Calls `f` with an exclusive reference to the value behind `handle`.
+    pub fn get_mut<R>(&self, handle: u64, f: impl FnOnce(&mut T) -> R) -> Result<R, FFIError> {
+        let (map_id, generation, index) = unpack(handle);
+        if map_id != self.map_id {
+            return Err(FFIError::Null);
+        }
+
+        let entries = self.entries.read().expect("handle map lock poisoned");
+        let slot = entries.get(index as usize).ok_or(FFIError::Null)?;
+
+        if slot.generation != generation {
+            return Err(FFIError::Null);
+        }
+
+        let value = slot.value.as_ref().ok_or(FFIError::Null)?;
+
+        Ok(f(&mut value.lock().expect("handle map lock poisoned")))
+    }
+
+    /// Removes and returns the value behind `handle`, bumping the slot's generation so no handle
+    /// to it (old or new) ever validates again until it is reinserted into.
+    pub fn remove(&self, handle: u64) -> Result<T, FFIError> {
+        let (map_id, generation, index) = unpack(handle);
+        if map_id != self.map_id {
+            return Err(FFIError::Null);
+        }
+
+        let mut entries = self.entries.write().expect("handle map lock poisoned");
+        let slot = entries.get_mut(index as usize).ok_or(FFIError::Null)?;
+
+        if slot.generation != generation || slot.value.is_none() {
+            return Err(FFIError::Null);
+        }
+
+        let value = slot
+            .value
+            .take()
+            .expect("checked above")
+            .into_inner()
+            .expect("handle map lock poisoned");
+
+        slot.generation = slot.generation.wrapping_add(1);
+
+        Ok(value)
+    }
+}
+
+impl<T> Default for HandleMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn pack(map_id: u16, generation: u16, index: u32) -> u64 {
+    ((map_id as u64) << 48) | ((generation as u64) << 32) | (index as u64)
+}
+
+fn unpack(handle: u64) -> (u16, u16, u32) {
+    let map_id = (handle >> 48) as u16;
+    let generation = (handle >> 32) as u16;
+    let index = handle as u32;
+
+    (map_id, generation, index)
+}
@@ -1,29 +1,46 @@
 use std::{
     ffi::{c_char, CStr, CString},
     ptr::null,
+    sync::OnceLock,
 };
 
 use interoptopus::{
     callback, ffi_function, ffi_service, ffi_service_ctor, ffi_type, function, pattern,
-    patterns::{
-        api_guard::APIVersion, option::FFIOption, slice::FFISliceMut, string::AsciiPointer,
-    },
+    patterns::{api_guard::APIVersion, option::FFIOption, string::AsciiPointer},
     Inventory, InventoryBuilder,
 };
 
-use crate::ffi_error::FFIError;
+use prost::Message;
+
+use crate::{
+    byte_buffer::ByteBuffer, extern_error::ExternError, ffi_error::FFIError, future::FutureHandle, handle_map::HandleMap,
+};
+
+pub mod byte_buffer;
+pub mod extern_error;
+pub mod future;
+mod handle_map;
+mod proto;
 
 callback!(DebugLogCallback(debug_log: AsciiPointer));
 
+// Callback to C# to asynchronously fetch a value for `key`; C# calls `complete_future` with
+// `future` once it has an answer, instead of returning it directly. This unblocks callers (e.g.
+// Unity) that cannot answer a storage query on the calling thread.
+callback!(GetStorageAsyncCallback(key: AsciiPointer, future: FutureHandle));
+
 #[derive(Debug)]
 pub enum EnvError {
     Other(String),
 }
 
-#[ffi_type(opaque)]
-#[repr(C)]
+/// A `CoreService` is no longer handed to C# as a raw opaque pointer. Instead it lives in
+/// [`core_services`] and C# only ever holds the `u64` handle returned by a `core_initialize_*`
+/// function, which [`HandleMap`] validates on every `core_*` call. This closes the use-after-free
+/// and double-free hole a bare pointer would otherwise allow.
 pub struct CoreService {
     storage: Option<StorageI>,
+    async_get_callback: Option<GetStorageAsyncCallback>,
 }
 
 enum StorageI {
@@ -31,42 +48,48 @@ enum StorageI {
     Ascii(StorageAscii),
 }
 
-#[ffi_service(error = "FFIError", prefix = "core_")]
+fn core_services() -> &'static HandleMap<CoreService> {
+    static CORE_SERVICES: OnceLock<HandleMap<CoreService>> = OnceLock::new();
+
+    CORE_SERVICES.get_or_init(HandleMap::new)
+}
+
 impl CoreService {
     /// Takes a Storage instance to be used in the [`CSharpEnv`] impl
     ///
     /// On panic it should return an error because of the Service impl of [`interoptopus`].
-    #[ffi_service_ctor]
-    pub fn initialize_native_with_debug_call(debug_callback: DebugLogCallback) -> Result<Self, EnvError> {
+    fn initialize_native_with_debug_call(debug_callback: DebugLogCallback) -> Result<Self, EnvError> {
         debug_callback.call(AsciiPointer::from_cstr(
             CString::new("debug callback has been triggered")
                 .map_err(|_| EnvError::Other("doesn't work".into()))?
                 .as_c_str(),
         ));
 
-        Ok(Self { storage: None })
+        Ok(Self {
+            storage: None,
+            async_get_callback: None,
+        })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_with_set(storage: Storage) -> Result<Self, EnvError> {
+    fn initialize_with_storage_with_set(storage: Storage) -> Result<Self, EnvError> {
         storage.storage_set("key", Some("value".into()));
 
         Ok(Self {
             storage: Some(StorageI::Bare(storage)),
+            async_get_callback: None,
         })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_with_get(storage: Storage) -> Result<Self, EnvError> {
+    fn initialize_with_storage_with_get(storage: Storage) -> Result<Self, EnvError> {
         let _value = storage.storage_get("key");
 
         Ok(Self {
             storage: Some(StorageI::Bare(storage)),
+            async_get_callback: None,
         })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_without_set_get(
+    fn initialize_with_storage_without_set_get(
         storage: Storage,
         debug_callback: DebugLogCallback,
     ) -> Result<Self, EnvError> {
@@ -78,29 +101,29 @@ impl CoreService {
 
         Ok(Self {
             storage: Some(StorageI::Bare(storage)),
+            async_get_callback: None,
         })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_ascii_with_set(storage: StorageAscii) -> Result<Self, EnvError> {
+    fn initialize_with_storage_ascii_with_set(storage: StorageAscii) -> Result<Self, EnvError> {
         storage.storage_set("key", Some("value".into()));
 
         Ok(Self {
             storage: Some(StorageI::Ascii(storage)),
+            async_get_callback: None,
         })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_ascii_with_get(storage: StorageAscii) -> Result<Self, EnvError> {
+    fn initialize_with_storage_ascii_with_get(storage: StorageAscii) -> Result<Self, EnvError> {
         let _value = storage.storage_get("key");
 
         Ok(Self {
             storage: Some(StorageI::Ascii(storage)),
+            async_get_callback: None,
         })
     }
 
-    #[ffi_service_ctor]
-    pub fn initialize_with_storage_ascii_without_set_get(
+    fn initialize_with_storage_ascii_without_set_get(
         storage: StorageAscii,
         debug_callback: DebugLogCallback,
     ) -> Result<Self, EnvError> {
@@ -112,10 +135,147 @@ impl CoreService {
 
         Ok(Self {
             storage: Some(StorageI::Ascii(storage)),
+            async_get_callback: None,
+        })
+    }
+
+    fn initialize_with_async_storage(async_get_callback: GetStorageAsyncCallback) -> Result<Self, EnvError> {
+        Ok(Self {
+            storage: None,
+            async_get_callback: Some(async_get_callback),
         })
     }
 }
 
+/// Builds a [`CoreService`] via `ctor` and stores it in [`core_services`], returning the handle
+/// C# should use for subsequent `core_*` calls.
+fn core_initialize(ctor: impl FnOnce() -> Result<CoreService, EnvError>, handle_out: &mut u64) -> ExternError {
+    catch_unwind_as_error(|| match ctor() {
+        Ok(service) => {
+            *handle_out = core_services().insert(service);
+            ExternError::ok()
+        }
+        Err(err) => err.into(),
+    })
+}
+
+/// Takes ownership of an opaque FFI value from the owning pointer a `*_new` constructor returned.
+///
+/// # Safety
+///
+/// `ptr` must be non-null and a still-live pointer previously returned by the matching `*_new`
+/// constructor (e.g. `storage_new`/`storage_ascii_new`), not yet consumed or freed elsewhere.
+unsafe fn take_boxed<T>(ptr: *mut T) -> T {
+    *Box::from_raw(ptr)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_native_with_debug_call(debug_callback: DebugLogCallback, handle_out: &mut u64) -> ExternError {
+    core_initialize(|| CoreService::initialize_native_with_debug_call(debug_callback), handle_out)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_with_set(storage: *mut Storage, handle_out: &mut u64) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(|| CoreService::initialize_with_storage_with_set(storage), handle_out)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_with_get(storage: *mut Storage, handle_out: &mut u64) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(|| CoreService::initialize_with_storage_with_get(storage), handle_out)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_without_set_get(
+    storage: *mut Storage,
+    debug_callback: DebugLogCallback,
+    handle_out: &mut u64,
+) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(
+        || CoreService::initialize_with_storage_without_set_get(storage, debug_callback),
+        handle_out,
+    )
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_ascii_with_set(storage: *mut StorageAscii, handle_out: &mut u64) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(|| CoreService::initialize_with_storage_ascii_with_set(storage), handle_out)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_ascii_with_get(storage: *mut StorageAscii, handle_out: &mut u64) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(|| CoreService::initialize_with_storage_ascii_with_get(storage), handle_out)
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_storage_ascii_without_set_get(
+    storage: *mut StorageAscii,
+    debug_callback: DebugLogCallback,
+    handle_out: &mut u64,
+) -> ExternError {
+    let storage = unsafe { take_boxed(storage) };
+    core_initialize(
+        || CoreService::initialize_with_storage_ascii_without_set_get(storage, debug_callback),
+        handle_out,
+    )
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_initialize_with_async_storage(async_get_callback: GetStorageAsyncCallback, handle_out: &mut u64) -> ExternError {
+    core_initialize(|| CoreService::initialize_with_async_storage(async_get_callback), handle_out)
+}
+
+/// Asynchronously fetches `key` through the `CoreService` behind `handle`.
+///
+/// Rather than blocking on the calling thread, this registers a pending future and invokes
+/// `async_get_callback` with its handle; C# answers later via `complete_future`. The returned
+/// [`FutureHandle`] can then be passed to `future_await` (blocking) or `future_try_take`
+/// (polling). If `handle` is stale, or the service wasn't constructed with an async callback,
+/// the returned future is already cancelled and both of those will report it as unknown.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_get_async(handle: u64, key: AsciiPointer) -> FutureHandle {
+    let future_handle = future::register();
+
+    // Only clone the callback while the handle map's per-entry lock is held; call it afterwards,
+    // since a callback that reenters `core_*` for the same handle on this thread would otherwise
+    // deadlock on that (non-reentrant) lock.
+    let callback = core_services().get(handle, |service| service.async_get_callback.clone());
+
+    let called = match callback {
+        Ok(Some(callback)) => {
+            callback.call(key, future_handle);
+            true
+        }
+        _ => false,
+    };
+
+    if !called {
+        future::cancel(future_handle);
+    }
+
+    future_handle
+}
+
+/// Releases the `CoreService` behind `handle`. A stale or already-freed handle is a no-op.
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn core_free(handle: u64) {
+    let _ = core_services().remove(handle);
+}
+
 // Callback to C# to get a given key from Storage
 callback!(GetStorageCallbackAscii(key: AsciiPointer) -> AsciiPointer<'static>);
 callback!(GetStorageCallback(key: *const c_char) -> *const c_char);
@@ -124,12 +284,29 @@ callback!(GetStorageCallback(key: *const c_char) -> *const c_char);
 callback!(SetStorageCallbackAscii(key: AsciiPointer, value: AsciiPointer));
 callback!(SetStorageCallback(key: *const c_char, value: *const c_char));
 
+// Callbacks for the binary, protobuf-encoded storage path. Values may contain embedded NUL
+// bytes, so these move a [`ByteBuffer`] rather than a `CString`/[`AsciiPointer`].
+//
+// Ownership: `GetStorageProtoCallback` returns a `ByteBuffer` that C# allocated and keeps owning -
+// same as `GetStorageCallbackAscii`/`GetStorageCallback`'s returned pointer, which `storage_get`
+// only ever reads through, never frees. `ffi_get_proto_impl` copies the bytes it needs out of that
+// buffer and otherwise leaves it alone; it must not be passed to `free_byte_buffer`, since that
+// deallocates with Rust's allocator and C#'s allocation was never made through it.
+//
+// `SetStorageProtoCallback`'s `value` is the opposite direction: Rust allocates it (via
+// `ByteBuffer::from_vec`) and hands ownership to C#, which must free it with `free_byte_buffer`
+// once it has read the bytes out.
+callback!(GetStorageProtoCallback(key: AsciiPointer) -> ByteBuffer);
+callback!(SetStorageProtoCallback(key: AsciiPointer, value: ByteBuffer));
+
 #[derive(Default)]
 #[ffi_type(opaque)]
 #[repr(C)]
 pub struct StorageAscii {
     get_callback: GetStorageCallbackAscii,
     set_callback: SetStorageCallbackAscii,
+    get_proto_callback: GetStorageProtoCallback,
+    set_proto_callback: SetStorageProtoCallback,
 }
 
 #[ffi_service(error = "FFIError", prefix = "storage_ascii_")]
@@ -138,43 +315,132 @@ impl StorageAscii {
     pub fn new(
         get_callback: GetStorageCallbackAscii,
         set_callback: SetStorageCallbackAscii,
+        get_proto_callback: GetStorageProtoCallback,
+        set_proto_callback: SetStorageProtoCallback,
     ) -> Result<Self, EnvError> {
         Ok(Self {
             get_callback,
             set_callback,
+            get_proto_callback,
+            set_proto_callback,
         })
     }
+}
 
-    pub fn ffi_set(&self, key: AsciiPointer, value: AsciiPointer) -> Result<(), EnvError> {
-        let value = value.as_c_str();
+// `ffi_service` only supports a C-like error enum (`FFIError`) in its error slot, since it relies
+// on `E::SUCCESS`/`E::PANIC` to build the wrapper; `ExternError` is a plain struct and doesn't
+// implement that trait. The methods below return the richer `ExternError` instead, so - like
+// `core_*` in chunk0-2 - they're plain `#[ffi_function]`s taking `storage` by reference (the same
+// shape `ffi_service` would have generated for `&self`) rather than methods inside the service.
+//
+// Being plain functions also means they lost `ffi_service`'s own `catch_unwind` guard around each
+// call, so the `#[ffi_function]` wrappers below run their body through [`catch_unwind_as_error`]
+// instead - a panic unwinding across an `extern "C"` boundary is undefined behavior.
+fn catch_unwind_as_error(f: impl FnOnce() -> ExternError) -> ExternError {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|_| EnvError::Other("panicked while handling FFI call".to_string()).into())
+}
 
-        self.storage_set(
-            key.as_str().expect("Should be valid UTF-8"),
-            value.map(|cstr| cstr.to_str().expect("Should be valid UTF-8").to_string()),
-        );
+impl StorageAscii {
+    fn ffi_set_impl(&self, key: AsciiPointer, value: AsciiPointer) -> Result<(), EnvError> {
+        let key = key.as_str().map_err(|err| EnvError::Other(err.to_string()))?;
+
+        let value = value
+            .as_c_str()
+            .map(|cstr| cstr.to_str().map(ToString::to_string).map_err(|err| EnvError::Other(err.to_string())))
+            .transpose()?;
+
+        self.storage_set(key, value);
 
         Ok(())
     }
 
-    /// if key is empty (`null` in C#) in storage we return `None` and json will be `null`` as well
-    pub fn ffi_get(
-        &self,
-        key: AsciiPointer,
-        mut result: FFISliceMut<u8>,
-        result_written: &mut u64,
-    ) -> Result<(), EnvError> {
-        let value = self.storage_get(key.as_str().expect("Valid UTF-8"))?;
+    /// Returns an empty [`ByteBuffer`] (`len == 0`, `data` null) if `key` is not present in
+    /// storage, otherwise the JSON-encoded value.
+    ///
+    /// Unlike the old fixed-slice version of this function, the caller does not need to guess a
+    /// buffer size up front: Rust allocates exactly as much as the value needs.
+    fn ffi_get_impl(&self, key: AsciiPointer) -> Result<ByteBuffer, EnvError> {
+        let key = key.as_str().map_err(|err| EnvError::Other(err.to_string()))?;
+        let value = self.storage_get(key)?;
 
-        let json = value.unwrap_or(serde_json::to_string(&serde_json::Value::Null).unwrap());
+        match value {
+            Some(json) => Ok(ByteBuffer::from_vec(json.into_bytes())),
+            None => Ok(ByteBuffer::empty()),
+        }
+    }
 
-        let json_cstring = CString::new(json).unwrap();
+    /// Binary-safe counterpart to [`Self::ffi_set_impl`]/[`Self::ffi_get_impl`]: `value` is a
+    /// [`proto::StorageValue`]-encoded [`ByteBuffer`] instead of a NUL-terminated JSON string, so
+    /// values may contain arbitrary bytes. The envelope is unwrapped here; `set_proto_callback`
+    /// only ever sees the raw inner bytes, not the `StorageValue` encoding.
+    fn ffi_set_proto_impl(&self, key: AsciiPointer, value: ByteBuffer) -> Result<(), EnvError> {
+        let bytes = unsafe { value.as_slice() };
+        let message = proto::StorageValue::decode(bytes).map_err(|err| EnvError::Other(err.to_string()))?;
 
-        result.as_slice_mut()[..json_cstring.as_bytes_with_nul().len()]
-            .copy_from_slice(json_cstring.as_bytes_with_nul());
-        *result_written = json_cstring.as_bytes_with_nul().len() as u64;
+        self.set_proto_callback.call(key, ByteBuffer::from_vec(message.value));
 
         Ok(())
     }
+
+    /// Returns an empty [`ByteBuffer`] if `key` is not present, otherwise the value wrapped in a
+    /// [`proto::StorageValue`] envelope.
+    ///
+    /// Note the envelope is only applied on this side of the boundary: `get_proto_callback` hands
+    /// back the raw inner bytes, and this method is the one that wraps them in `StorageValue` before
+    /// returning the encoded envelope to the FFI caller - the callback never sees the envelope.
+    ///
+    /// `raw` is owned by C#, same as the ASCII `get_callback`'s returned pointer (see
+    /// `storage_get`) - it's only read through here, never freed.
+    fn ffi_get_proto_impl(&self, key: AsciiPointer) -> Result<ByteBuffer, EnvError> {
+        let raw = self.get_proto_callback.call(key);
+        let value = unsafe { raw.as_slice() }.to_vec();
+
+        let message = proto::StorageValue { value };
+
+        Ok(ByteBuffer::from_vec(message.encode_to_vec()))
+    }
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ascii_ffi_set(storage: &StorageAscii, key: AsciiPointer, value: AsciiPointer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_set_impl(key, value) {
+        Ok(()) => ExternError::ok(),
+        Err(err) => err.into(),
+    })
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ascii_ffi_get(storage: &StorageAscii, key: AsciiPointer, result_out: &mut ByteBuffer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_get_impl(key) {
+        Ok(buffer) => {
+            *result_out = buffer;
+            ExternError::ok()
+        }
+        Err(err) => err.into(),
+    })
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ascii_ffi_set_proto(storage: &StorageAscii, key: AsciiPointer, value: ByteBuffer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_set_proto_impl(key, value) {
+        Ok(()) => ExternError::ok(),
+        Err(err) => err.into(),
+    })
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ascii_ffi_get_proto(storage: &StorageAscii, key: AsciiPointer, result_out: &mut ByteBuffer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_get_proto_impl(key) {
+        Ok(buffer) => {
+            *result_out = buffer;
+            ExternError::ok()
+        }
+        Err(err) => err.into(),
+    })
 }
 
 impl StorageAscii {
@@ -214,6 +480,8 @@ impl StorageAscii {
 pub struct Storage {
     get_callback: GetStorageCallback,
     set_callback: SetStorageCallback,
+    get_proto_callback: GetStorageProtoCallback,
+    set_proto_callback: SetStorageProtoCallback,
 }
 
 #[ffi_service(error = "FFIError", prefix = "storage_")]
@@ -222,14 +490,72 @@ impl Storage {
     pub fn new(
         get_callback: GetStorageCallback,
         set_callback: SetStorageCallback,
+        get_proto_callback: GetStorageProtoCallback,
+        set_proto_callback: SetStorageProtoCallback,
     ) -> Result<Self, EnvError> {
         Ok(Self {
             get_callback,
             set_callback,
+            get_proto_callback,
+            set_proto_callback,
         })
     }
 }
 
+// See the matching comment on `StorageAscii`: `ExternError` can't sit in `ffi_service`'s error
+// slot, so these are plain functions taking `storage` by reference instead of service methods.
+impl Storage {
+    /// Binary-safe counterpart to the ASCII get/set callbacks: exchanges a
+    /// [`proto::StorageValue`]-encoded [`ByteBuffer`] instead of a `*const c_char`.
+    fn ffi_set_proto_impl(&self, key: AsciiPointer, value: ByteBuffer) -> Result<(), EnvError> {
+        let bytes = unsafe { value.as_slice() };
+        let message = proto::StorageValue::decode(bytes).map_err(|err| EnvError::Other(err.to_string()))?;
+
+        self.set_proto_callback.call(key, ByteBuffer::from_vec(message.value));
+
+        Ok(())
+    }
+
+    /// Returns an empty [`ByteBuffer`] if `key` is not present, otherwise the value wrapped in a
+    /// [`proto::StorageValue`] envelope.
+    ///
+    /// Note the envelope is only applied on this side of the boundary: `get_proto_callback` hands
+    /// back the raw inner bytes, and this method is the one that wraps them in `StorageValue` before
+    /// returning the encoded envelope to the FFI caller - the callback never sees the envelope.
+    ///
+    /// `raw` is owned by C#, same as the ASCII `get_callback`'s returned pointer (see
+    /// `storage_get`) - it's only read through here, never freed.
+    fn ffi_get_proto_impl(&self, key: AsciiPointer) -> Result<ByteBuffer, EnvError> {
+        let raw = self.get_proto_callback.call(key);
+        let value = unsafe { raw.as_slice() }.to_vec();
+
+        let message = proto::StorageValue { value };
+
+        Ok(ByteBuffer::from_vec(message.encode_to_vec()))
+    }
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ffi_set_proto(storage: &Storage, key: AsciiPointer, value: ByteBuffer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_set_proto_impl(key, value) {
+        Ok(()) => ExternError::ok(),
+        Err(err) => err.into(),
+    })
+}
+
+#[ffi_function]
+#[no_mangle]
+pub extern "C" fn storage_ffi_get_proto(storage: &Storage, key: AsciiPointer, result_out: &mut ByteBuffer) -> ExternError {
+    catch_unwind_as_error(|| match storage.ffi_get_proto_impl(key) {
+        Ok(buffer) => {
+            *result_out = buffer;
+            ExternError::ok()
+        }
+        Err(err) => err.into(),
+    })
+}
+
 impl Storage {
     fn storage_set(&self, key: &str, value: Option<String>) {
         let key = CString::new(key).unwrap();
@@ -304,11 +630,35 @@ pub fn my_inventory() -> Inventory {
         // Register main ffi functions
         // api_guard fails on Android for some reason
         .register(function!(api_guard))
+        .register(function!(extern_error::free_extern_error_message))
+        .register(function!(byte_buffer::free_byte_buffer))
         // register Storage service
         .register(pattern!(Storage))
         .register(pattern!(StorageAscii))
-        // register the Core service
-        .register(pattern!(CoreService))
+        // register the ExternError-returning functions that couldn't live inside the services
+        // above (see the comments on `StorageAscii`/`Storage`)
+        .register(function!(storage_ascii_ffi_set))
+        .register(function!(storage_ascii_ffi_get))
+        .register(function!(storage_ascii_ffi_set_proto))
+        .register(function!(storage_ascii_ffi_get_proto))
+        .register(function!(storage_ffi_set_proto))
+        .register(function!(storage_ffi_get_proto))
+        // register the Core service's handle-based functions
+        .register(function!(core_initialize_native_with_debug_call))
+        .register(function!(core_initialize_with_storage_with_set))
+        .register(function!(core_initialize_with_storage_with_get))
+        .register(function!(core_initialize_with_storage_without_set_get))
+        .register(function!(core_initialize_with_storage_ascii_with_set))
+        .register(function!(core_initialize_with_storage_ascii_with_get))
+        .register(function!(core_initialize_with_storage_ascii_without_set_get))
+        .register(function!(core_initialize_with_async_storage))
+        .register(function!(core_get_async))
+        .register(function!(core_free))
+        // register the future handle functions backing `core_get_async`
+        .register(function!(future::complete_future))
+        .register(function!(future::future_await))
+        .register(function!(future::future_try_take))
+        .register(function!(future::future_cancel))
         .inventory()
 }
 
@@ -0,0 +1,6 @@
+//! Generated from `proto/storage.proto` by `prost-build` (see `build.rs`). [`StorageValue`] is
+//! the envelope the `*_proto` storage functions encode/decode on the Rust side of the FFI
+//! boundary; the `get`/`set` callbacks C# implements exchange only the raw inner bytes, never the
+//! encoded envelope itself.
+
+include!(concat!(env!("OUT_DIR"), "/core.storage.rs"));